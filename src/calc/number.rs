@@ -1,4 +1,8 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
 
 /// Metric prefix and the factor.
 const METRIC_PREFIXES: [(&str, i32); 10] = [
@@ -14,11 +18,352 @@ const METRIC_PREFIXES: [(&str, i32); 10] = [
     ("P", 15),
 ];
 
+/// An exact fixed-point decimal, stored as `mantissa * 10^-dps`. Used by [`Value::Fixed`] to
+/// avoid the rounding artifacts `f64` accumulates over many chained operations.
+#[derive(Copy, Clone, Debug)]
+pub struct Fixed {
+    mantissa: i128,
+    dps: u32,
+}
+
+impl PartialEq for Fixed {
+    /// Compares the represented values, not the raw `(mantissa, dps)` fields, so values at
+    /// different scales (e.g. `1:1` vs `10:2`) compare equal.
+    fn eq(&self, other: &Self) -> bool {
+        let dps = self.dps.max(other.dps);
+        self.rescale(dps).mantissa == other.rescale(dps).mantissa
+    }
+}
+
+impl Eq for Fixed {}
+
+impl Fixed {
+    /// Builds a fixed-point value from a raw scaled mantissa and its decimal places.
+    pub fn new(mantissa: i128, dps: u32) -> Self {
+        Self { mantissa, dps }
+    }
+
+    fn scale(dps: u32) -> i128 {
+        10i128.pow(dps)
+    }
+
+    /// Converts an `f64` into its nearest fixed-point representation at `dps` decimal places.
+    ///
+    /// Non-finite inputs (`NaN`, `+-inf`) have no exact scaled-integer representation; they
+    /// saturate to `0` rather than to `i128::MAX`/`MIN`, so that a single non-finite operand
+    /// doesn't poison every later arithmetic op or `display` call with an overflow panic.
+    pub fn from_f64(v: f64, dps: u32) -> Self {
+        if !v.is_finite() {
+            return Fixed::new(0, dps);
+        }
+        Fixed::new((v * Self::scale(dps) as f64).round() as i128, dps)
+    }
+
+    /// Converts back to an `f64`, e.g. for display or interop with float-based code.
+    pub fn to_f64(self) -> f64 {
+        self.mantissa as f64 / Self::scale(self.dps) as f64
+    }
+
+    /// Rescales to `dps` decimal places, rounding half away from zero when narrowing.
+    pub fn rescale(self, dps: u32) -> Self {
+        if dps == self.dps {
+            self
+        } else if dps > self.dps {
+            Fixed::new(self.mantissa * Self::scale(dps - self.dps), dps)
+        } else {
+            let factor = Self::scale(self.dps - dps);
+            let half = factor / 2;
+            let mantissa = if self.mantissa >= 0 {
+                (self.mantissa + half) / factor
+            } else {
+                -((-self.mantissa + half) / factor)
+            };
+            Fixed::new(mantissa, dps)
+        }
+    }
+
+    /// Rescales `self` and `rhs` to a shared number of decimal places.
+    fn common_dps(self, rhs: Self) -> (i128, i128, u32) {
+        let dps = self.dps.max(rhs.dps);
+        (self.rescale(dps).mantissa, rhs.rescale(dps).mantissa, dps)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (a, b, dps) = self.common_dps(rhs);
+        Fixed::new(a + b, dps)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (a, b, dps) = self.common_dps(rhs);
+        Fixed::new(a - b, dps)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let dps = self.dps.max(rhs.dps);
+        let product = self.mantissa * rhs.mantissa;
+        let factor = Self::scale(self.dps + rhs.dps - dps);
+        let half = factor / 2;
+        let mantissa = if product >= 0 {
+            (product + half) / factor
+        } else {
+            -((-product + half) / factor)
+        };
+        Fixed::new(mantissa, dps)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let dps = self.dps.max(rhs.dps);
+        if rhs.mantissa == 0 {
+            // No integer representation of infinity/NaN: fall back to `f64`, which `from_f64`
+            // saturates to `0` rather than a near-`i128::MAX` mantissa, so the result stays a
+            // usable value for any later `rescale`/`display` instead of deferring a panic.
+            return Fixed::from_f64(self.to_f64() / rhs.to_f64(), dps);
+        }
+
+        let numerator = self.mantissa * Self::scale(dps + rhs.dps - self.dps);
+        let denominator = rhs.mantissa;
+        let half = denominator.abs() / 2;
+        let mantissa = if (numerator >= 0) == (denominator >= 0) {
+            (numerator.abs() + half) / denominator.abs()
+        } else {
+            -((numerator.abs() + half) / denominator.abs())
+        };
+        Fixed::new(mantissa, dps)
+    }
+}
+
+impl Rem for Fixed {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        if rhs.mantissa == 0 {
+            let dps = self.dps.max(rhs.dps);
+            return Fixed::from_f64(self.to_f64() % rhs.to_f64(), dps);
+        }
+
+        let (a, b, dps) = self.common_dps(rhs);
+        Fixed::new(a % b, dps)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Fixed::new(-self.mantissa, self.dps)
+    }
+}
+
+/// The numeric representation backing a [`Num`]: either a raw `f64`, or an exact fixed-point
+/// decimal (see [`Fixed`]) for callers that need to avoid `f64`'s rounding artifacts.
+#[derive(Copy, Clone, Debug)]
+pub enum Value {
+    Float(f64),
+    Fixed(Fixed),
+}
+
+impl PartialEq for Value {
+    /// Compares the represented numeric value, so a `Fixed` and a `Float` holding the same
+    /// number (e.g. both zero) compare equal regardless of backend.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Fixed(a), Value::Fixed(b)) => a == b,
+            _ => self.to_f64() == other.to_f64(),
+        }
+    }
+}
+
+impl Value {
+    /// Converts to an `f64`, regardless of backend.
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Value::Float(v) => v,
+            Value::Fixed(v) => v.to_f64(),
+        }
+    }
+
+    fn map_f64(self, f: impl FnOnce(f64) -> f64) -> Self {
+        match self {
+            Value::Float(v) => Value::Float(f(v)),
+            Value::Fixed(v) => Value::Fixed(Fixed::from_f64(f(v.to_f64()), v.dps)),
+        }
+    }
+
+    /// Returns the value's square root.
+    pub fn sqrt(self) -> Self {
+        self.map_f64(f64::sqrt)
+    }
+
+    /// Raises the value to an integer power, negative exponents included.
+    pub fn powi(self, n: i32) -> Self {
+        self.map_f64(|v| v.powi(n))
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(self) -> Self {
+        match self {
+            Value::Float(v) => Value::Float(v.abs()),
+            Value::Fixed(v) => Value::Fixed(Fixed::new(v.mantissa.abs(), v.dps)),
+        }
+    }
+
+    /// Returns the sign as `-1` or `1`, matching `f64::signum` — including for zero, which
+    /// signs as `1` (or `-1` for `-0.0`), never `0`.
+    pub fn signum(self) -> Self {
+        self.map_f64(f64::signum)
+    }
+
+    pub fn is_sign_positive(self) -> bool {
+        self.to_f64() > 0.0
+    }
+
+    pub fn is_sign_negative(self) -> bool {
+        self.to_f64() < 0.0
+    }
+}
+
+impl Add for Value {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::Fixed(a), Value::Fixed(b)) => Value::Fixed(a + b),
+            _ => Value::Float(self.to_f64() + rhs.to_f64()),
+        }
+    }
+}
+
+impl Add<f64> for Value {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self::Output {
+        match self {
+            Value::Float(v) => Value::Float(v + rhs),
+            Value::Fixed(v) => Value::Fixed(v + Fixed::from_f64(rhs, v.dps)),
+        }
+    }
+}
+
+impl Sub for Value {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::Fixed(a), Value::Fixed(b)) => Value::Fixed(a - b),
+            _ => Value::Float(self.to_f64() - rhs.to_f64()),
+        }
+    }
+}
+
+impl Sub<f64> for Value {
+    type Output = Self;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        match self {
+            Value::Float(v) => Value::Float(v - rhs),
+            Value::Fixed(v) => Value::Fixed(v - Fixed::from_f64(rhs, v.dps)),
+        }
+    }
+}
+
+impl Mul for Value {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::Fixed(a), Value::Fixed(b)) => Value::Fixed(a * b),
+            _ => Value::Float(self.to_f64() * rhs.to_f64()),
+        }
+    }
+}
+
+impl Mul<f64> for Value {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        match self {
+            Value::Float(v) => Value::Float(v * rhs),
+            Value::Fixed(v) => Value::Fixed(v * Fixed::from_f64(rhs, v.dps)),
+        }
+    }
+}
+
+impl Div for Value {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::Fixed(a), Value::Fixed(b)) => Value::Fixed(a / b),
+            _ => Value::Float(self.to_f64() / rhs.to_f64()),
+        }
+    }
+}
+
+impl Div<f64> for Value {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        match self {
+            Value::Float(v) => Value::Float(v / rhs),
+            Value::Fixed(v) => Value::Fixed(v / Fixed::from_f64(rhs, v.dps)),
+        }
+    }
+}
+
+impl Rem for Value {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::Fixed(a), Value::Fixed(b)) => Value::Fixed(a % b),
+            _ => Value::Float(self.to_f64() % rhs.to_f64()),
+        }
+    }
+}
+
+impl Rem<f64> for Value {
+    type Output = Self;
+
+    fn rem(self, rhs: f64) -> Self::Output {
+        match self {
+            Value::Float(v) => Value::Float(v % rhs),
+            Value::Fixed(v) => Value::Fixed(v % Fixed::from_f64(rhs, v.dps)),
+        }
+    }
+}
+
+impl Neg for Value {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Value::Float(v) => Value::Float(-v),
+            Value::Fixed(v) => Value::Fixed(-v),
+        }
+    }
+}
+
 /// A enum that holds either an input, output or no number at all.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Num {
-    In(f64),
-    Out(f64),
+    In(Value),
+    Out(Value),
     None,
 }
 
@@ -59,14 +404,19 @@ impl Num {
     /// Returns the number as an option.
     pub fn as_option(&self) -> Option<f64> {
         match self {
-            Num::In(v) => Some(*v),
-            Num::Out(v) => Some(*v),
+            Num::In(v) => Some(v.to_f64()),
+            Num::Out(v) => Some(v.to_f64()),
             Num::None => None,
         }
     }
 
     /// Returns the numbers value if there is one panics otherwise.
     pub fn num(&self) -> f64 {
+        self.value().to_f64()
+    }
+
+    /// Returns the numbers underlying [`Value`] if there is one panics otherwise.
+    pub fn value(&self) -> Value {
         match self {
             Num::In(v) => *v,
             Num::Out(v) => *v,
@@ -83,76 +433,191 @@ impl Num {
         }
     }
 
+    /// Raises the number to an integer power. Negative exponents are handled as the
+    /// reciprocal of the positive power.
+    pub fn powi(&self, n: i32) -> Self {
+        match self {
+            Num::In(v) => Num::In(v.powi(n)),
+            Num::Out(v) => Num::Out(v.powi(n)),
+            Num::None => Num::None,
+        }
+    }
+
     /// Returns the number formatted as a string with a metric prefix and the specified number of
     /// significant figures.
     pub fn display(&self, significant_figures: usize) -> String {
-        if self.is_num() {
-            let mut num = self.num();
-            let mut metric_prefix = ' ';
+        match self {
+            Num::In(Value::Fixed(fx)) | Num::Out(Value::Fixed(fx)) => {
+                Self::display_fixed(*fx, significant_figures)
+            }
+            Num::In(Value::Float(_)) | Num::Out(Value::Float(_)) => {
+                let mut num = self.num();
+                let mut metric_prefix = ' ';
 
-            for m in &METRIC_PREFIXES {
-                let factor = 10_f64.powi(m.1);
+                for m in &METRIC_PREFIXES {
+                    let factor = 10_f64.powi(m.1);
 
-                if num.abs() / factor >= 1.0 && num.abs() / factor < 1000.0 {
-                    num /= factor;
-                    metric_prefix = m.0.chars().next().unwrap();
-                    break;
+                    if num.abs() / factor >= 1.0 && num.abs() / factor < 1000.0 {
+                        num /= factor;
+                        metric_prefix = m.0.chars().next().unwrap();
+                        break;
+                    }
                 }
-            }
 
-            let integer_figures = num.abs().log10().floor() as usize + 1;
-            let floating_figures = if integer_figures > significant_figures {
-                0
-            } else {
-                significant_figures - integer_figures
-            };
+                let integer_figures = num.abs().log10().floor() as usize + 1;
+                let floating_figures = if integer_figures > significant_figures {
+                    0
+                } else {
+                    significant_figures - integer_figures
+                };
 
-            format!("{0:.1$}{2}", num, floating_figures, metric_prefix)
-        } else {
-            String::new()
+                format!("{0:.1$}{2}", num, floating_figures, metric_prefix)
+            }
+            Num::None => String::new(),
         }
     }
 
-    /// Returns the number formatted as a ratio string.
-    pub fn display_ratio(&self) -> String {
-        if self.is_num() {
-            let num = self.num();
-            let mut temp = self.num();
-            let mut a: i64 = 1;
-            let b: i64;
-
-            while temp.fract() > 0.0001 && temp.fract() < 0.9999 {
-                a += 1;
-                temp += num;
+    /// Formats a fixed-point value with a metric prefix. Unlike the `f64` path, the displayed
+    /// digits are read directly off the scaled integer mantissa instead of round-tripping
+    /// through `f64`; only picking which metric prefix bucket to use is done approximately.
+    fn display_fixed(fx: Fixed, significant_figures: usize) -> String {
+        let approx = fx.to_f64().abs();
+        let mut exp = 0;
+        let mut metric_prefix = ' ';
+
+        for m in &METRIC_PREFIXES {
+            let factor = 10_f64.powi(m.1);
+
+            if approx / factor >= 1.0 && approx / factor < 1000.0 {
+                exp = m.1;
+                metric_prefix = m.0.chars().next().unwrap();
+                break;
             }
+        }
 
-            b = temp.round() as i64;
+        // Applying a metric prefix just shifts the decimal point, which is exact in the
+        // scaled-integer representation.
+        let scaled_dps = fx.dps as i32 + exp;
+        let scaled = if scaled_dps >= 0 {
+            Fixed::new(fx.mantissa, scaled_dps as u32)
+        } else {
+            Fixed::new(fx.mantissa * Fixed::scale((-scaled_dps) as u32), 0)
+        };
+
+        let whole = scaled.mantissa.abs() / Fixed::scale(scaled.dps);
+        let integer_figures = whole.to_string().len();
+        let floating_figures = significant_figures.saturating_sub(integer_figures) as u32;
+
+        let rounded = scaled.rescale(floating_figures);
+        let sign = if rounded.mantissa < 0 { "-" } else { "" };
+        let mantissa = rounded.mantissa.abs();
+        let scale = Fixed::scale(rounded.dps);
+        let int_part = mantissa / scale;
+        let frac_part = mantissa % scale;
 
-            format!("{}:{}", a, b)
+        if floating_figures == 0 {
+            format!("{}{}{}", sign, int_part, metric_prefix)
+        } else {
+            format!(
+                "{}{}.{:0width$}{}",
+                sign,
+                int_part,
+                frac_part,
+                metric_prefix,
+                width = floating_figures as usize
+            )
+        }
+    }
+
+    /// Returns the number formatted as a ratio string, approximated as the closest rational
+    /// with a denominator no greater than `max_denominator`.
+    ///
+    /// Follows the `denominator:numerator` convention used by `parse_ratio` (i.e. the number
+    /// `h/k` is printed as `k:h`), so that `parse_ratio(n.display_ratio(d))` round-trips.
+    pub fn display_ratio(&self, max_denominator: i64) -> String {
+        if self.is_num() {
+            let (h, k) = Self::best_rational(self.num(), max_denominator);
+            format!("{}:{}", k, h)
         } else {
             String::new()
         }
     }
 
-    /// Parses a number from the string.
-    pub fn parse(str: impl Into<String>) -> Self {
-        let mut s = str.into().replace(",", ".");
-        let mut factor = 1.0;
+    /// Finds the rational `h/k` closest to `x` with `k <= max_denominator`, by building
+    /// continued-fraction convergents.
+    ///
+    /// Non-finite `x` (`NaN`, `+-inf`, reachable e.g. through `0.0 / 0.0`) has no continued
+    /// fraction expansion and would spin the loop below forever, so it short-circuits to `0:1`.
+    fn best_rational(x: f64, max_denominator: i64) -> (i64, i64) {
+        if !x.is_finite() {
+            return (0, 1);
+        }
 
-        'outer: for m in &METRIC_PREFIXES {
-            for c in m.0.chars() {
-                if s.ends_with(c) {
-                    s.pop();
-                    factor = 10_f64.powi(m.1);
-                    break 'outer;
-                }
+        let sign = if x < 0.0 { -1 } else { 1 };
+        let max_denominator = max_denominator as i128;
+
+        let mut t = x.abs();
+        // Seeded per the standard continued-fraction recurrence: h_-1 = 1, h_-2 = 0,
+        // k_-1 = 0, k_-2 = 1.
+        //
+        // The accumulators are `i128`, not `i64`: a run of consecutive partial quotients of 1
+        // (e.g. the golden ratio) combined with a `max_denominator` near `i64::MAX` can make
+        // `h`/`k` overflow `i64` well before `k` exceeds `max_denominator`.
+        let (mut h_prev2, mut h_prev1) = (0_i128, 1_i128);
+        let (mut k_prev2, mut k_prev1) = (1_i128, 0_i128);
+
+        loop {
+            let a = t.floor() as i128;
+            let h = a * h_prev1 + h_prev2;
+            let k = a * k_prev1 + k_prev2;
+
+            if k > max_denominator {
+                let a_semi = if k_prev1 > 0 {
+                    (max_denominator - k_prev2) / k_prev1
+                } else {
+                    0
+                };
+                let h_semi = a_semi * h_prev1 + h_prev2;
+                let k_semi = a_semi * k_prev1 + k_prev2;
+
+                let full_err = (x.abs() - h_prev1 as f64 / k_prev1 as f64).abs();
+                let semi_err = (x.abs() - h_semi as f64 / k_semi as f64).abs();
+
+                return if k_semi > 0 && semi_err <= full_err {
+                    (sign * h_semi as i64, k_semi as i64)
+                } else {
+                    (sign * h_prev1 as i64, k_prev1 as i64)
+                };
+            }
+
+            let frac = t - a as f64;
+            if frac < 1e-9 {
+                return (sign * h as i64, k as i64);
             }
+
+            h_prev2 = h_prev1;
+            h_prev1 = h;
+            k_prev2 = k_prev1;
+            k_prev1 = k;
+
+            t = 1.0 / frac;
         }
+    }
 
-        if let Ok(v) = s.parse::<f64>() {
-            Num::In(v * factor)
-        } else {
-            Num::None
+    /// Parses a number from the string, discarding the reason on failure.
+    ///
+    /// Use the `FromStr` impl directly if the cause of a failed parse is needed.
+    pub fn parse(str: impl Into<String>) -> Self {
+        str.into().parse::<Num>().unwrap_or(Num::None)
+    }
+
+    /// Parses a number from the string into the exact fixed-point backend, at `dps` decimal
+    /// places, discarding the reason on failure.
+    pub fn parse_fixed(str: impl Into<String>, dps: u32) -> Self {
+        match str.into().parse::<Num>() {
+            Ok(Num::In(v)) => Num::In(Value::Fixed(Fixed::from_f64(v.to_f64(), dps))),
+            Ok(Num::Out(v)) => Num::Out(Value::Fixed(Fixed::from_f64(v.to_f64(), dps))),
+            _ => Num::None,
         }
     }
 
@@ -173,6 +638,84 @@ impl Num {
     }
 }
 
+/// The reason a string failed to parse as a [`Num`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseNumError {
+    /// The string was empty (or only whitespace).
+    Empty,
+    /// The numeric part couldn't be parsed as a float.
+    InvalidFloat(std::num::ParseFloatError),
+    /// The trailing letter matches more than one metric prefix with different factors.
+    ///
+    /// Unreachable with the current [`METRIC_PREFIXES`] table, where every character belongs
+    /// to exactly one entry; reserved for if the table ever grows an overlapping alias.
+    AmbiguousPrefix,
+    /// The string ends in a letter that isn't a recognized metric prefix.
+    TrailingGarbage,
+    /// `from_str_radix` was called with a radix other than 10, which the metric-aware parser
+    /// doesn't support.
+    UnsupportedRadix(u32),
+}
+
+impl fmt::Display for ParseNumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNumError::Empty => write!(f, "number is empty"),
+            ParseNumError::InvalidFloat(e) => write!(f, "invalid number: {}", e),
+            ParseNumError::AmbiguousPrefix => write!(f, "ambiguous metric prefix"),
+            ParseNumError::TrailingGarbage => write!(f, "unrecognized metric prefix"),
+            ParseNumError::UnsupportedRadix(radix) => {
+                write!(f, "unsupported radix {}, only base 10 is supported", radix)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseNumError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseNumError::InvalidFloat(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Num {
+    type Err = ParseNumError;
+
+    /// Parses a number from the string.
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let trimmed = str.trim();
+        if trimmed.is_empty() {
+            return Err(ParseNumError::Empty);
+        }
+
+        let mut s = trimmed.replace(",", ".");
+        let mut factor = 1.0;
+
+        if let Some(last) = s.chars().last().filter(|c| c.is_alphabetic()) {
+            let mut factors: Vec<i32> = METRIC_PREFIXES
+                .iter()
+                .filter(|m| m.0.contains(last))
+                .map(|m| m.1)
+                .collect();
+            factors.dedup();
+
+            match factors.as_slice() {
+                [] => return Err(ParseNumError::TrailingGarbage),
+                [exp] => {
+                    s.pop();
+                    factor = 10_f64.powi(*exp);
+                }
+                _ => return Err(ParseNumError::AmbiguousPrefix),
+            }
+        }
+
+        let v = s.parse::<f64>().map_err(ParseNumError::InvalidFloat)?;
+        Ok(Num::In(Value::Float(v * factor)))
+    }
+}
+
 impl Add<f64> for Num {
     type Output = Self;
 
@@ -192,8 +735,8 @@ impl Add<Num> for Num {
         if rhs.is_none() { return Num::None; }
 
         match self {
-            Num::In(v) => Num::In(v + rhs.num()),
-            Num::Out(v) => Num::Out(v + rhs.num()),
+            Num::In(v) => Num::In(v + rhs.value()),
+            Num::Out(v) => Num::Out(v + rhs.value()),
             Num::None => Num::None,
         }
     }
@@ -218,8 +761,8 @@ impl Sub<Num> for Num {
         if rhs.is_none() { return Num::None; }
 
         match self {
-            Num::In(v) => Num::In(v - rhs.num()),
-            Num::Out(v) => Num::Out(v - rhs.num()),
+            Num::In(v) => Num::In(v - rhs.value()),
+            Num::Out(v) => Num::Out(v - rhs.value()),
             Num::None => Num::None,
         }
     }
@@ -244,8 +787,8 @@ impl Mul<Num> for Num {
         if rhs.is_none() { return Num::None; }
 
         match self {
-            Num::In(v) => Num::In(v * rhs.num()),
-            Num::Out(v) => Num::Out(v * rhs.num()),
+            Num::In(v) => Num::In(v * rhs.value()),
+            Num::Out(v) => Num::Out(v * rhs.value()),
             Num::None => Num::None,
         }
     }
@@ -270,9 +813,303 @@ impl Div<Num> for Num {
         if rhs.is_none() { return Num::None; }
 
         match self {
-            Num::In(v) => Num::In(v / rhs.num()),
-            Num::Out(v) => Num::Out(v / rhs.num()),
+            Num::In(v) => Num::In(v / rhs.value()),
+            Num::Out(v) => Num::Out(v / rhs.value()),
             Num::None => Num::None,
         }
     }
 }
+
+impl Rem<f64> for Num {
+    type Output = Self;
+
+    fn rem(self, rhs: f64) -> Self::Output {
+        match self {
+            Num::In(v) => Num::In(v % rhs),
+            Num::Out(v) => Num::Out(v % rhs),
+            Num::None => Num::None,
+        }
+    }
+}
+
+impl Rem<Num> for Num {
+    type Output = Self;
+
+    fn rem(self, rhs: Num) -> Self::Output {
+        if rhs.is_none() { return Num::None; }
+
+        match self {
+            Num::In(v) => Num::In(v % rhs.value()),
+            Num::Out(v) => Num::Out(v % rhs.value()),
+            Num::None => Num::None,
+        }
+    }
+}
+
+impl Neg for Num {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Num::In(v) => Num::In(-v),
+            Num::Out(v) => Num::Out(-v),
+            Num::None => Num::None,
+        }
+    }
+}
+
+impl Zero for Num {
+    fn zero() -> Self {
+        Num::In(Value::Float(0.0))
+    }
+
+    fn is_zero(&self) -> bool {
+        matches!(self.as_option(), Some(v) if v == 0.0)
+    }
+}
+
+impl One for Num {
+    fn one() -> Self {
+        Num::In(Value::Float(1.0))
+    }
+}
+
+impl num_traits::Num for Num {
+    type FromStrRadixErr = ParseNumError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(ParseNumError::UnsupportedRadix(radix));
+        }
+        str.parse::<Num>()
+    }
+}
+
+impl Signed for Num {
+    fn abs(&self) -> Self {
+        match self {
+            Num::In(v) => Num::In(v.abs()),
+            Num::Out(v) => Num::Out(v.abs()),
+            Num::None => Num::None,
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        let diff = *self - *other;
+        if diff.is_num() && diff.num() < 0.0 {
+            Self::zero()
+        } else {
+            diff
+        }
+    }
+
+    fn signum(&self) -> Self {
+        match self {
+            Num::In(v) => Num::In(v.signum()),
+            Num::Out(v) => Num::Out(v.signum()),
+            Num::None => Num::None,
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        matches!(self, Num::In(v) | Num::Out(v) if v.is_sign_positive())
+    }
+
+    fn is_negative(&self) -> bool {
+        matches!(self, Num::In(v) | Num::Out(v) if v.is_sign_negative())
+    }
+}
+
+impl ToPrimitive for Num {
+    fn to_i64(&self) -> Option<i64> {
+        self.as_option().map(|v| v as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.as_option().and_then(|v| if v >= 0.0 { Some(v as u64) } else { None })
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.as_option()
+    }
+}
+
+impl FromPrimitive for Num {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Num::In(Value::Float(n as f64)))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Num::In(Value::Float(n as f64)))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Num::In(Value::Float(n)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_rational_of_zero() {
+        assert_eq!(Num::best_rational(0.0, 1000), (0, 1));
+    }
+
+    #[test]
+    fn best_rational_of_negative() {
+        assert_eq!(Num::best_rational(-1.5, 1000), (-3, 2));
+    }
+
+    #[test]
+    fn best_rational_respects_max_denominator() {
+        let (h, k) = Num::best_rational(std::f64::consts::PI, 10);
+        assert!(k <= 10);
+        assert_eq!((h, k), (22, 7));
+    }
+
+    #[test]
+    fn best_rational_of_non_finite_does_not_hang() {
+        assert_eq!(Num::best_rational(f64::NAN, 1000), (0, 1));
+        assert_eq!(Num::best_rational(f64::INFINITY, 1000), (0, 1));
+        assert_eq!(Num::best_rational(f64::NEG_INFINITY, 1000), (0, 1));
+    }
+
+    #[test]
+    fn best_rational_does_not_overflow_on_long_runs_of_ones() {
+        // The golden ratio's continued fraction is all 1s, so its convergents' denominators
+        // (Fibonacci numbers) grow as slowly as possible and, paired with a `max_denominator`
+        // near `i64::MAX`, used to overflow the `i64` accumulators before `k` caught up.
+        let golden_ratio = (1.0 + 5f64.sqrt()) / 2.0;
+        let (h, k) = Num::best_rational(golden_ratio, i64::MAX);
+        assert!(k <= i64::MAX);
+        assert!((golden_ratio - h as f64 / k as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_div_by_zero_is_usable() {
+        let five = Fixed::from_f64(5.0, 2);
+        let zero = Fixed::from_f64(0.0, 2);
+        let result = five / zero;
+        // Shouldn't panic on further arithmetic or display.
+        let _ = result.rescale(4);
+        assert_eq!(Num::display_fixed(result, 4), "0.000 ");
+    }
+
+    #[test]
+    fn fixed_rem_by_zero_is_usable() {
+        let five = Fixed::from_f64(5.0, 2);
+        let zero = Fixed::from_f64(0.0, 2);
+        let result = five % zero;
+        let _ = result.rescale(4);
+    }
+
+    #[test]
+    fn fixed_from_f64_saturates_non_finite() {
+        assert_eq!(Fixed::from_f64(f64::NAN, 2).mantissa, 0);
+        assert_eq!(Fixed::from_f64(f64::INFINITY, 2).mantissa, 0);
+        assert_eq!(Fixed::from_f64(f64::NEG_INFINITY, 2).mantissa, 0);
+    }
+
+    #[test]
+    fn fixed_arithmetic_across_scales() {
+        let a = Fixed::new(150, 2); // 1.50
+        let b = Fixed::new(5, 1); // 0.5
+        assert_eq!(a + b, Fixed::new(200, 2));
+        assert_eq!(a - b, Fixed::new(100, 2));
+        assert_eq!((a * b).to_f64(), 0.75);
+        assert_eq!((a / b).to_f64(), 3.0);
+    }
+
+    #[test]
+    fn fixed_rescale_rounds_half_up_for_negative() {
+        let v = Fixed::new(-125, 2); // -1.25
+        assert_eq!(v.rescale(1), Fixed::new(-13, 1)); // -1.3
+    }
+
+    #[test]
+    fn from_str_parses_plain_and_metric_numbers() {
+        assert_eq!("1.5".parse::<Num>().unwrap(), Num::In(Value::Float(1.5)));
+        assert_eq!("1,5".parse::<Num>().unwrap(), Num::In(Value::Float(1.5)));
+        assert_eq!("1.5k".parse::<Num>().unwrap(), Num::In(Value::Float(1500.0)));
+    }
+
+    #[test]
+    fn from_str_rejects_empty() {
+        assert_eq!("".parse::<Num>(), Err(ParseNumError::Empty));
+        assert_eq!("   ".parse::<Num>(), Err(ParseNumError::Empty));
+    }
+
+    #[test]
+    fn from_str_rejects_invalid_float() {
+        assert!(matches!(
+            "1.2.3".parse::<Num>(),
+            Err(ParseNumError::InvalidFloat(_))
+        ));
+    }
+
+    #[test]
+    fn from_str_rejects_trailing_garbage() {
+        assert_eq!("5q".parse::<Num>(), Err(ParseNumError::TrailingGarbage));
+    }
+
+    #[test]
+    fn from_str_radix_rejects_non_decimal_radix() {
+        assert_eq!(
+            <Num as num_traits::Num>::from_str_radix("10", 16),
+            Err(ParseNumError::UnsupportedRadix(16))
+        );
+        assert_eq!(
+            <Num as num_traits::Num>::from_str_radix("10", 10),
+            Ok(Num::In(Value::Float(10.0)))
+        );
+    }
+
+    #[test]
+    fn parse_maps_parse_error_to_none() {
+        assert_eq!(Num::parse(""), Num::None);
+        assert_eq!(Num::parse("5q"), Num::None);
+    }
+
+    #[test]
+    fn powi_handles_negative_exponents() {
+        let two = Num::In(Value::Float(2.0));
+        assert_eq!(two.powi(3).num(), 8.0);
+        assert_eq!(two.powi(-1).num(), 0.5);
+        assert_eq!(two.powi(-2).num(), 0.25);
+    }
+
+    #[test]
+    fn powi_propagates_none() {
+        assert_eq!(Num::None.powi(-1), Num::None);
+    }
+
+    #[test]
+    fn signed_abs_sub_clamps_to_zero() {
+        let small = Num::In(Value::Float(1.0));
+        let big = Num::In(Value::Float(5.0));
+        assert_eq!(small.abs_sub(&big), Num::zero());
+        assert_eq!(big.abs_sub(&small), Num::In(Value::Float(4.0)));
+    }
+
+    #[test]
+    fn signed_is_positive_and_is_negative() {
+        assert!(Num::In(Value::Float(1.0)).is_positive());
+        assert!(!Num::In(Value::Float(-1.0)).is_positive());
+        assert!(Num::In(Value::Float(-1.0)).is_negative());
+        assert!(!Num::In(Value::Float(1.0)).is_negative());
+        assert!(!Num::None.is_positive());
+        assert!(!Num::None.is_negative());
+    }
+
+    #[test]
+    fn to_primitive_and_from_primitive_round_trip() {
+        let n = Num::In(Value::Float(42.0));
+        assert_eq!(n.to_i64(), Some(42));
+        assert_eq!(n.to_u64(), Some(42));
+        assert_eq!(Num::None.to_i64(), None);
+        assert_eq!(Num::from_i64(42), Some(Num::In(Value::Float(42.0))));
+        assert_eq!(Num::from_f64(1.5), Some(Num::In(Value::Float(1.5))));
+    }
+}